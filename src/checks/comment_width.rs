@@ -0,0 +1,74 @@
+//! The comment-width check: comments are held to a tighter width than
+//! general code, via [`Config::comment_width`]/[`Config::doc_comment_width`].
+
+use std::path::Path;
+
+use crate::config::Config;
+
+use super::url;
+use super::{CheckError, CheckKind};
+
+pub fn check_line(path: &Path, line_no: usize, text: &str, config: &Config) -> Option<CheckError> {
+    let trimmed = text.trim_start();
+    let (limit, label) = if trimmed.starts_with("///") || trimmed.starts_with("//!") {
+        (config.doc_comment_width, "doc comment")
+    } else if trimmed.starts_with("//") {
+        (config.comment_width, "comment")
+    } else {
+        return None;
+    };
+
+    let width = text.chars().count();
+    if width <= limit {
+        return None;
+    }
+
+    if url::is_url_exempt(text) {
+        return None;
+    }
+
+    Some(CheckError {
+        file: path.to_path_buf(),
+        line: line_no,
+        column: limit + 1,
+        kind: CheckKind::CommentWidth,
+        message: format!("{label} is {width} characters wide, exceeds the {limit} limit"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn config_with_comment_width(width: usize) -> Config {
+        Config {
+            comment_width: width,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn flags_a_comment_that_exceeds_the_width_limit() {
+        let config = config_with_comment_width(20);
+        let error = check_line(
+            &PathBuf::from("test.rs"),
+            1,
+            "// this comment is much too long to fit on one line",
+            &config,
+        );
+        assert!(error.is_some());
+    }
+
+    #[test]
+    fn exempts_a_standalone_url_comment_even_when_it_exceeds_the_limit() {
+        let config = config_with_comment_width(20);
+        let error = check_line(
+            &PathBuf::from("test.rs"),
+            1,
+            "// https://example.com/some/very/long/path/that/is/over/the/limit",
+            &config,
+        );
+        assert!(error.is_none());
+    }
+}
@@ -0,0 +1,45 @@
+//! The multi-line-import check: flags `use` groups that span more than one
+//! physical line, e.g. `use crate::example::{ One, Two };` written across
+//! several lines instead of one.
+
+use std::path::Path;
+
+use super::{CheckError, CheckKind};
+
+/// Tracks whether we're currently inside a braced `use` group that started
+/// on an earlier line.
+#[derive(Default)]
+pub struct ImportChecker {
+    group_start: Option<usize>,
+}
+
+impl ImportChecker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds the next line to the checker, returning an error once a
+    /// multi-line group has been fully consumed.
+    pub fn feed(&mut self, path: &Path, line_no: usize, text: &str) -> Option<CheckError> {
+        if let Some(start_line) = self.group_start {
+            if text.contains('}') {
+                self.group_start = None;
+                return Some(CheckError {
+                    file: path.to_path_buf(),
+                    line: start_line,
+                    column: 1,
+                    kind: CheckKind::Imports,
+                    message: "multi-line `use` group should fit on a single line".to_string(),
+                });
+            }
+            return None;
+        }
+
+        let trimmed = text.trim_start();
+        if trimmed.starts_with("use ") && trimmed.contains('{') && !trimmed.contains('}') {
+            self.group_start = Some(line_no);
+        }
+
+        None
+    }
+}
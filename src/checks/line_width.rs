@@ -0,0 +1,28 @@
+//! The line-width check: flags lines wider than [`Config::max_width`].
+
+use std::path::Path;
+
+use crate::config::Config;
+
+use super::url;
+use super::{CheckError, CheckKind};
+
+pub fn check_line(path: &Path, line_no: usize, text: &str, config: &Config) -> Option<CheckError> {
+    let width = text.chars().count();
+    if width <= config.max_width {
+        return None;
+    }
+
+    if url::is_url_exempt(text) {
+        return None;
+    }
+
+    let max_width = config.max_width;
+    Some(CheckError {
+        file: path.to_path_buf(),
+        line: line_no,
+        column: max_width + 1,
+        kind: CheckKind::LineLength,
+        message: format!("line is {width} characters wide, exceeds the {max_width} limit"),
+    })
+}
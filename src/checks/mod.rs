@@ -0,0 +1,137 @@
+//! Individual style checks and the shared per-line iteration that drives them.
+
+pub mod comment_width;
+pub mod imports;
+pub mod line_width;
+pub mod todo;
+pub mod url;
+pub mod whitespace;
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::directives;
+
+/// A single named check. The name is also what's recognised in `ignore-style`
+/// directives, so adding a check here and giving it a name is enough to wire
+/// it into the suppression system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckKind {
+    LineLength,
+    Imports,
+    TrailingWhitespace,
+    HardTab,
+    LineEndings,
+    CommentWidth,
+    Todo,
+    /// Not itself suppressible: reported when a directive names an unknown check.
+    Directive,
+}
+
+impl CheckKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            CheckKind::LineLength => "linelength",
+            CheckKind::Imports => "imports",
+            CheckKind::TrailingWhitespace => "trailingwhitespace",
+            CheckKind::HardTab => "hardtab",
+            CheckKind::LineEndings => "crlf",
+            CheckKind::CommentWidth => "commentwidth",
+            CheckKind::Todo => "todo",
+            CheckKind::Directive => "directive",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "linelength" => Some(CheckKind::LineLength),
+            "imports" => Some(CheckKind::Imports),
+            "trailingwhitespace" => Some(CheckKind::TrailingWhitespace),
+            "hardtab" => Some(CheckKind::HardTab),
+            "crlf" => Some(CheckKind::LineEndings),
+            "commentwidth" => Some(CheckKind::CommentWidth),
+            "todo" => Some(CheckKind::Todo),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckError {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub kind: CheckKind,
+    pub message: String,
+}
+
+impl fmt::Display for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "::error file={},line={},col={}::[{}] {}",
+            self.file.display(),
+            self.line,
+            self.column,
+            self.kind.name(),
+            self.message
+        )
+    }
+}
+
+/// Runs every check over `content` in a single pass over its lines, then
+/// filters out anything suppressed by an `ignore-style` directive.
+pub fn run_checks(path: &Path, content: &str, config: &Config) -> Vec<CheckError> {
+    let lines: Vec<&str> = content.lines().collect();
+    let (directives, mut errors) = directives::parse(path, &lines);
+
+    let mut imports = imports::ImportChecker::new();
+    for (idx, text) in lines.iter().enumerate() {
+        let line_no = idx + 1;
+
+        if let Some(error) = line_width::check_line(path, line_no, text, config) {
+            if !directives.suppresses(line_no, CheckKind::LineLength) {
+                errors.push(error);
+            }
+        }
+
+        if let Some(error) = comment_width::check_line(path, line_no, text, config) {
+            if !directives.suppresses(line_no, CheckKind::CommentWidth) {
+                errors.push(error);
+            }
+        }
+
+        if let Some(error) = imports.feed(path, line_no, text) {
+            if !directives.suppresses(error.line, CheckKind::Imports) {
+                errors.push(error);
+            }
+        }
+
+        if let Some(error) = whitespace::check_trailing_whitespace(path, line_no, text) {
+            if !directives.suppresses(line_no, CheckKind::TrailingWhitespace) {
+                errors.push(error);
+            }
+        }
+
+        if let Some(error) = whitespace::check_hard_tab(path, line_no, text) {
+            if !directives.suppresses(line_no, CheckKind::HardTab) {
+                errors.push(error);
+            }
+        }
+
+        if let Some(error) = todo::check_line(path, line_no, text, config) {
+            if !directives.suppresses(line_no, CheckKind::Todo) {
+                errors.push(error);
+            }
+        }
+    }
+
+    if let Some(error) = whitespace::check_line_endings(path, content) {
+        if !directives.suppresses(error.line, CheckKind::LineEndings) {
+            errors.push(error);
+        }
+    }
+
+    errors
+}
@@ -0,0 +1,105 @@
+//! The TODO/FIXME/XXX marker check: following tidy's "no TODO or XXX
+//! directives" rule, flags these markers so they surface in CI review.
+//! Only matches inside comments, as whole words, so string literals like
+//! `"TODO list"` don't produce noise.
+
+use std::path::Path;
+
+use crate::comments::comment_content;
+use crate::config::Config;
+
+use super::{CheckError, CheckKind};
+
+pub fn check_line(path: &Path, line_no: usize, text: &str, config: &Config) -> Option<CheckError> {
+    if config.allow_todo {
+        return None;
+    }
+
+    let (comment_chars, start) = comment_content(text)?;
+    let comment_text: String = comment_chars.into_iter().collect();
+
+    for marker in &config.todo_markers {
+        if let Some(offset) = find_word(&comment_text, marker) {
+            return Some(CheckError {
+                file: path.to_path_buf(),
+                line: line_no,
+                column: start + offset + 1,
+                kind: CheckKind::Todo,
+                message: format!("found a `{marker}` marker; resolve it before merging"),
+            });
+        }
+    }
+
+    None
+}
+
+/// Finds `word` inside `text` as a whole word (not a substring of a longer
+/// identifier), returning its char offset.
+fn find_word(text: &str, word: &str) -> Option<usize> {
+    let chars: Vec<char> = text.chars().collect();
+    let word_chars: Vec<char> = word.chars().collect();
+    if word_chars.is_empty() || word_chars.len() > chars.len() {
+        return None;
+    }
+
+    for start in 0..=chars.len() - word_chars.len() {
+        if chars[start..start + word_chars.len()] != word_chars[..] {
+            continue;
+        }
+
+        let before_ok = start == 0 || !chars[start - 1].is_alphanumeric();
+        let after = start + word_chars.len();
+        let after_ok = after == chars.len() || !chars[after].is_alphanumeric();
+        if before_ok && after_ok {
+            return Some(start);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn flags_a_todo_marker_in_a_comment() {
+        let error = check_line(&PathBuf::from("test.rs"), 1, "// TODO: fix this", &Config::default());
+        assert!(error.is_some());
+    }
+
+    #[test]
+    fn ignores_a_marker_inside_a_string_literal() {
+        let text = "let list = \"TODO list\";";
+        let error = check_line(&PathBuf::from("test.rs"), 1, text, &Config::default());
+        assert!(error.is_none());
+    }
+
+    #[test]
+    fn does_not_match_a_marker_as_a_substring_of_a_longer_word() {
+        let error = check_line(&PathBuf::from("test.rs"), 1, "// TODOS: fix these", &Config::default());
+        assert!(error.is_none());
+    }
+
+    #[test]
+    fn allow_todo_suppresses_the_check() {
+        let config = Config {
+            allow_todo: true,
+            ..Config::default()
+        };
+        let error = check_line(&PathBuf::from("test.rs"), 1, "// TODO: fix this", &config);
+        assert!(error.is_none());
+    }
+
+    #[test]
+    fn honours_a_custom_todo_markers_list() {
+        let config = Config {
+            todo_markers: vec!["HACK".to_string()],
+            ..Config::default()
+        };
+
+        assert!(check_line(&PathBuf::from("test.rs"), 1, "// TODO: fix this", &config).is_none());
+        assert!(check_line(&PathBuf::from("test.rs"), 1, "// HACK: fix this", &config).is_some());
+    }
+}
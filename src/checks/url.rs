@@ -0,0 +1,98 @@
+//! Recognises lines that are essentially a single URL in a comment, so the
+//! line-width check can exempt them. Implements the small state machine
+//! rustc's tidy tool uses for the same purpose: strip an optional comment
+//! marker, then require what's left to reduce to a single URL token,
+//! optionally preceded by a short label.
+
+const COMMENT_MARKERS: [&str; 3] = ["///", "//", "/*"];
+const URL_SCHEMES: [&str; 3] = ["http://", "https://", "ftp://"];
+
+enum State<'a> {
+    CommentStart(&'a str),
+    LabelOrUrl(&'a str),
+    Url(&'a str),
+}
+
+/// Returns `true` if `line` reduces to a single URL token, optionally
+/// preceded by a comment marker and a short label, e.g. `// https://a.b/c`
+/// or `// See: https://a.b/c`.
+pub fn is_url_exempt(line: &str) -> bool {
+    let mut state = State::CommentStart(line.trim());
+
+    loop {
+        state = match state {
+            State::CommentStart(rest) => {
+                let rest = match COMMENT_MARKERS.iter().find(|marker| rest.starts_with(*marker)) {
+                    Some(marker) => rest[marker.len()..].trim_start(),
+                    None => rest,
+                };
+                let rest = rest.trim_end().trim_end_matches("*/").trim_end();
+                State::LabelOrUrl(rest)
+            }
+
+            State::LabelOrUrl(rest) => {
+                let mut tokens = rest.splitn(2, char::is_whitespace);
+                match (tokens.next(), tokens.next()) {
+                    (Some(first), None) => return is_url_token(first),
+                    (Some(first), Some(remainder)) if !is_url_token(first) => {
+                        State::Url(remainder.trim_start())
+                    }
+                    _ => return false,
+                }
+            }
+
+            State::Url(rest) => {
+                let mut tokens = rest.split_whitespace();
+                return matches!(
+                    (tokens.next(), tokens.next()),
+                    (Some(token), None) if is_url_token(token)
+                );
+            }
+        };
+    }
+}
+
+fn is_url_token(token: &str) -> bool {
+    URL_SCHEMES.iter().any(|scheme| token.starts_with(scheme))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exempts_a_bare_url_comment() {
+        assert!(is_url_exempt(
+            "// https://example.com/some/very/long/path/that/is/definitely/over/the/limit"
+        ));
+    }
+
+    #[test]
+    fn exempts_a_url_with_a_short_label() {
+        assert!(is_url_exempt(
+            "// See: https://example.com/some/very/long/path/that/is/over/the/limit"
+        ));
+    }
+
+    #[test]
+    fn does_not_exempt_a_line_that_is_not_just_a_url() {
+        assert!(!is_url_exempt(
+            "// this is just a very long comment with no url in it at all, really"
+        ));
+    }
+
+    #[test]
+    fn does_not_exempt_a_label_followed_by_more_than_one_word_before_the_url() {
+        assert!(!is_url_exempt("// See also this: https://example.com/path"));
+    }
+
+    #[test]
+    fn does_not_exempt_a_url_with_trailing_text() {
+        assert!(!is_url_exempt("// https://example.com/path and some more text"));
+    }
+
+    #[test]
+    fn does_not_exempt_an_empty_comment() {
+        assert!(!is_url_exempt("//"));
+    }
+}
@@ -0,0 +1,65 @@
+//! Whitespace-hygiene checks: trailing whitespace, hard tabs, and carriage
+//! returns. These are cheap per-line (or, for line endings, per-file) scans
+//! that ride along the same iteration `checks::run_checks` already does for
+//! the line-width check.
+
+use std::path::Path;
+
+use super::{CheckError, CheckKind};
+
+pub fn check_trailing_whitespace(path: &Path, line_no: usize, text: &str) -> Option<CheckError> {
+    let trimmed = text.trim_end_matches([' ', '\t']);
+    if trimmed.len() == text.len() {
+        return None;
+    }
+
+    Some(CheckError {
+        file: path.to_path_buf(),
+        line: line_no,
+        column: trimmed.chars().count() + 1,
+        kind: CheckKind::TrailingWhitespace,
+        message: "line has trailing whitespace".to_string(),
+    })
+}
+
+pub fn check_hard_tab(path: &Path, line_no: usize, text: &str) -> Option<CheckError> {
+    let column = text.chars().position(|c| c == '\t')? + 1;
+
+    Some(CheckError {
+        file: path.to_path_buf(),
+        line: line_no,
+        column,
+        kind: CheckKind::HardTab,
+        message: "line contains a hard tab; use 4-space indentation instead".to_string(),
+    })
+}
+
+/// Scans the whole file for the first `\r`, which signals either a stray
+/// carriage return or CRLF line endings. Runs once per file rather than
+/// per-line since `str::lines()` already strips `\r` before a check would
+/// ever see it.
+pub fn check_line_endings(path: &Path, content: &str) -> Option<CheckError> {
+    let mut line = 1;
+    let mut column = 1;
+
+    for ch in content.chars() {
+        if ch == '\r' {
+            return Some(CheckError {
+                file: path.to_path_buf(),
+                line,
+                column,
+                kind: CheckKind::LineEndings,
+                message: "file contains a carriage return; use plain LF line endings".to_string(),
+            });
+        }
+
+        if ch == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+
+    None
+}
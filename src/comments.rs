@@ -0,0 +1,77 @@
+//! Token-aware comment detection shared by anything that needs to look at
+//! "the comment on this line" without being tripped up by `//`/`/*`-looking
+//! text inside a string literal.
+
+/// Finds the first `//`, `///`, or `/* */` comment on the line, skipping
+/// over string literals so a `//` or `/*` inside one isn't mistaken for the
+/// start of a comment. Returns the comment's characters (including its
+/// leading marker) and its char offset into the line.
+pub fn comment_content(line: &str) -> Option<(Vec<char>, usize)> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            i += 1;
+            continue;
+        }
+
+        if c == '"' {
+            in_string = true;
+            i += 1;
+            continue;
+        }
+
+        if c == '/' && chars.get(i + 1) == Some(&'/') {
+            return Some((chars[i..].to_vec(), i));
+        }
+
+        if c == '/' && chars.get(i + 1) == Some(&'*') {
+            let end = chars[i..]
+                .windows(2)
+                .position(|pair| pair == ['*', '/'])
+                .map_or(chars.len(), |pos| i + pos + 2);
+            return Some((chars[i..end].to_vec(), i));
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignores_comment_markers_inside_string_literals() {
+        let line = "const FILE_MARKER: &str = \"// ignore-style-file:\";";
+        assert_eq!(comment_content(line), None);
+    }
+
+    #[test]
+    fn finds_a_trailing_line_comment() {
+        let (chars, start) = comment_content("let x = 1; // TODO fix").unwrap();
+        assert_eq!(start, 11);
+        assert_eq!(chars.into_iter().collect::<String>(), "// TODO fix");
+    }
+
+    #[test]
+    fn finds_a_block_comment_and_stops_at_its_close() {
+        let (chars, start) = comment_content("let x /* TODO */ = 1;").unwrap();
+        assert_eq!(start, 6);
+        assert_eq!(chars.into_iter().collect::<String>(), "/* TODO */");
+    }
+}
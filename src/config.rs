@@ -0,0 +1,149 @@
+//! Per-project configuration, read from a `.stylecheck.toml` discovered by
+//! walking upward from the file being checked — the same discovery rustfmt
+//! uses for `rustfmt.toml`.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+const CONFIG_FILE_NAME: &str = ".stylecheck.toml";
+const DEFAULT_MAX_WIDTH: usize = 110;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub max_width: usize,
+    pub comment_width: usize,
+    pub doc_comment_width: usize,
+    pub allow_todo: bool,
+    pub todo_markers: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        RawConfig::default().resolve()
+    }
+}
+
+/// Mirrors the on-disk shape of `.stylecheck.toml`. Every field is optional
+/// so a file that only sets `max_width` still gets `comment_width` and
+/// `doc_comment_width` derived from it, rather than from the built-in
+/// default width.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct RawConfig {
+    max_width: Option<usize>,
+    comment_width: Option<usize>,
+    doc_comment_width: Option<usize>,
+    allow_todo: Option<bool>,
+    todo_markers: Option<Vec<String>>,
+}
+
+impl RawConfig {
+    fn resolve(self) -> Config {
+        let max_width = self.max_width.unwrap_or(DEFAULT_MAX_WIDTH);
+        Config {
+            max_width,
+            comment_width: self.comment_width.unwrap_or_else(|| comment_width_for(max_width)),
+            doc_comment_width: self.doc_comment_width.unwrap_or(max_width),
+            allow_todo: self.allow_todo.unwrap_or(false),
+            todo_markers: self.todo_markers.unwrap_or_else(default_todo_markers),
+        }
+    }
+}
+
+/// Surfaced when a discovered `.stylecheck.toml` fails to parse, so a typo
+/// in the config fails loud rather than silently falling back to defaults.
+#[derive(Debug)]
+pub struct ConfigError {
+    pub file: PathBuf,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "::error file={}::failed to parse config: {}",
+            self.file.display(),
+            self.message
+        )
+    }
+}
+
+fn default_todo_markers() -> Vec<String> {
+    ["TODO", "FIXME", "XXX"].into_iter().map(String::from).collect()
+}
+
+/// Derives the comment-width sub-limit from `max_width`, following
+/// rustfmt's width-heuristics model: comment prose is held to a tighter
+/// fraction of the line than code is.
+fn comment_width_for(max_width: usize) -> usize {
+    max_width * 80 / 100
+}
+
+/// Walks upward from `start` (a file or directory being checked) looking for
+/// a `.stylecheck.toml`, falling back to [`Config::default`] if none is
+/// found. A config file that fails to parse also falls back to defaults,
+/// but is reported via the returned [`ConfigError`] rather than swallowed.
+pub fn discover(start: &Path) -> (Config, Option<ConfigError>) {
+    let mut dir = if start.is_dir() {
+        Some(start.to_path_buf())
+    } else {
+        start.parent().map(Path::to_path_buf)
+    };
+
+    while let Some(candidate) = dir {
+        let config_path = candidate.join(CONFIG_FILE_NAME);
+        if let Ok(text) = fs::read_to_string(&config_path) {
+            return match toml::from_str::<RawConfig>(&text) {
+                Ok(raw) => (raw.resolve(), None),
+                Err(err) => (
+                    Config::default(),
+                    Some(ConfigError {
+                        file: config_path,
+                        message: err.to_string(),
+                    }),
+                ),
+            };
+        }
+        dir = candidate.parent().map(Path::to_path_buf);
+    }
+
+    (Config::default(), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn malformed_config_reports_an_error_and_falls_back_to_defaults() {
+        let dir = std::env::temp_dir().join("stylecheck-config-test-malformed");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(CONFIG_FILE_NAME), "max_width = not_a_number").unwrap();
+
+        let (config, error) = discover(&dir);
+
+        assert_eq!(config, Config::default());
+        assert!(error.is_some());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn valid_config_derives_sub_limits_from_max_width() {
+        let dir = std::env::temp_dir().join("stylecheck-config-test-valid");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(CONFIG_FILE_NAME), "max_width = 100").unwrap();
+
+        let (config, error) = discover(&dir);
+
+        assert!(error.is_none());
+        assert_eq!(config.max_width, 100);
+        assert_eq!(config.comment_width, 80);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,141 @@
+//! `// ignore-style:<check>` / `// ignore-style-file:<check>` directive
+//! parsing, borrowed from the approach rustc's tidy tool uses with its
+//! `// ignore-tidy-<check>` comments.
+//!
+//! Directives are collected in a first pass over the file, before any check
+//! runs, so that per-line and file-wide suppressions are known up front.
+//! Only the file's actual comments are considered — via the same
+//! string-literal-aware scan [`checks::todo`] uses — and a directive must
+//! be the whole comment (after its `//`/`///`/`/*` marker), so prose that
+//! merely mentions the syntax (e.g. in this module's own doc comments, or
+//! the marker constants below) is never mistaken for a real directive.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::checks::{CheckError, CheckKind};
+use crate::comments::comment_content;
+
+const COMMENT_MARKERS: [&str; 3] = ["///", "//", "/*"];
+const FILE_DIRECTIVE: &str = "ignore-style-file:";
+const LINE_DIRECTIVE: &str = "ignore-style:";
+
+pub struct Directives {
+    line: HashSet<(usize, CheckKind)>,
+    file: HashSet<CheckKind>,
+}
+
+impl Directives {
+    pub fn suppresses(&self, line: usize, kind: CheckKind) -> bool {
+        self.file.contains(&kind) || self.line.contains(&(line, kind))
+    }
+}
+
+/// Parses every `ignore-style` directive in `lines`, returning the resolved
+/// suppressions plus an error for each unknown check name (a typo there
+/// should not silently disable a check).
+pub fn parse(path: &Path, lines: &[&str]) -> (Directives, Vec<CheckError>) {
+    let mut line = HashSet::new();
+    let mut file = HashSet::new();
+    let mut errors = Vec::new();
+
+    for (idx, text) in lines.iter().enumerate() {
+        let line_no = idx + 1;
+
+        let Some((comment_chars, comment_start)) = comment_content(text) else {
+            continue;
+        };
+        let comment: String = comment_chars.into_iter().collect();
+
+        let mut body = comment.as_str();
+        if let Some(marker) = COMMENT_MARKERS.iter().find(|marker| body.starts_with(*marker)) {
+            body = body[marker.len()..].trim_start();
+        }
+
+        // File-level directives take priority: checked first since
+        // `ignore-style-file:` also starts with `ignore-style` as a prefix.
+        if let Some(names) = body.strip_prefix(FILE_DIRECTIVE) {
+            for name in split_names(names) {
+                match CheckKind::from_name(name) {
+                    Some(kind) => {
+                        file.insert(kind);
+                    }
+                    None => errors.push(unknown_check_error(path, line_no, comment_start, name)),
+                }
+            }
+        } else if let Some(names) = body.strip_prefix(LINE_DIRECTIVE) {
+            for name in split_names(names) {
+                match CheckKind::from_name(name) {
+                    Some(kind) => {
+                        line.insert((line_no, kind));
+                    }
+                    None => errors.push(unknown_check_error(path, line_no, comment_start, name)),
+                }
+            }
+        }
+    }
+
+    (Directives { line, file }, errors)
+}
+
+fn split_names(rest: &str) -> impl Iterator<Item = &str> {
+    rest.trim()
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+}
+
+fn unknown_check_error(path: &Path, line_no: usize, column: usize, name: &str) -> CheckError {
+    CheckError {
+        file: path.to_path_buf(),
+        line: line_no,
+        column: column + 1,
+        kind: CheckKind::Directive,
+        message: format!("unknown check `{name}` in ignore-style directive"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn parse_lines(lines: &[&str]) -> (Directives, Vec<CheckError>) {
+        parse(&PathBuf::from("test.rs"), lines)
+    }
+
+    #[test]
+    fn a_real_directive_suppresses_the_named_check_on_its_line() {
+        let (directives, errors) =
+            parse_lines(&["let x = 123456789012345; // ignore-style:linelength"]);
+
+        assert!(errors.is_empty());
+        assert!(directives.suppresses(1, CheckKind::LineLength));
+        assert!(!directives.suppresses(1, CheckKind::Imports));
+    }
+
+    #[test]
+    fn doc_comment_prose_mentioning_the_syntax_is_not_a_directive() {
+        let (directives, errors) = parse_lines(&[
+            "/// `// ignore-style:<check>` / `// ignore-style-file:<check>` directive",
+        ]);
+
+        assert!(errors.is_empty());
+        assert!(!directives.suppresses(1, CheckKind::LineLength));
+    }
+
+    #[test]
+    fn marker_text_inside_a_string_literal_is_not_a_directive() {
+        let (directives, errors) = parse_lines(&[
+            "const FILE_MARKER: &str = \"// ignore-style-file:\";",
+        ]);
+
+        assert!(errors.is_empty());
+        assert!(!directives.suppresses(1, CheckKind::LineLength));
+    }
+
+    #[test]
+    fn unknown_check_name_is_reported() {
+        let (_, errors) = parse_lines(&["// ignore-style:bogus"]);
+        assert_eq!(errors.len(), 1);
+    }
+}
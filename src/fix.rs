@@ -0,0 +1,305 @@
+//! `--fix` support: rewrites flagged multi-line `use` groups onto a single
+//! line, mirroring rustfmt's `imports_granularity = "One"` behaviour for the
+//! grouped case, and greedily wraps over-long comments down to the
+//! `comment_width`/`doc_comment_width` limits. Already-single-line groups
+//! and already-short comments are left untouched.
+//!
+//! Over-long string literals are deliberately left alone: breaking one up
+//! safely would mean rewriting it as a concatenation (`"a" "b"` line
+//! continuation or `"a".to_string() + "b"`), which risks changing the
+//! value (escapes, leading/trailing whitespace) in a way a comment reflow
+//! never does. `linelength` still flags these; `--fix` just won't touch
+//! them.
+
+use crate::checks::url;
+use crate::checks::CheckKind;
+use crate::config::Config;
+use crate::directives::Directives;
+
+/// Collapses every multi-line `use { ... }` group in `content` onto a single
+/// line. Correctly tracks nested braces when finding the end of a group,
+/// and drops any comments found inside one (they can't survive the merge).
+/// A group suppressed by an `ignore-style:imports`/`ignore-style-file:imports`
+/// directive at its start line is left untouched.
+pub fn fix_imports(content: &str, directives: &Directives) -> String {
+    let mut output = String::new();
+    let mut lines = content.lines().enumerate().peekable();
+
+    while let Some((idx, line)) = lines.next() {
+        let line_no = idx + 1;
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+
+        if code_without_comment(trimmed).starts_with("use ") && brace_delta(line) > 0 {
+            let mut group = vec![line.to_string()];
+            let mut depth = brace_delta(line);
+            while depth > 0 {
+                match lines.next() {
+                    Some((_, next)) => {
+                        depth += brace_delta(next);
+                        group.push(next.to_string());
+                    }
+                    None => break,
+                }
+            }
+
+            if directives.suppresses(line_no, CheckKind::Imports) {
+                for raw in &group {
+                    output.push_str(raw);
+                    output.push('\n');
+                }
+            } else {
+                output.push_str(&collapse_group(&group, indent));
+                output.push('\n');
+            }
+        } else {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+
+    if !content.ends_with('\n') {
+        output.pop();
+    }
+
+    output
+}
+
+fn code_without_comment(line: &str) -> &str {
+    match line.find("//") {
+        Some(idx) => &line[..idx],
+        None => line,
+    }
+}
+
+fn brace_delta(line: &str) -> i32 {
+    let code = code_without_comment(line);
+    code.chars().filter(|&c| c == '{').count() as i32 - code.chars().filter(|&c| c == '}').count() as i32
+}
+
+fn collapse_group(lines: &[String], indent: &str) -> String {
+    let joined = lines
+        .iter()
+        .map(|line| code_without_comment(line))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let normalized = joined.split_whitespace().collect::<Vec<_>>().join(" ");
+    let normalized = normalized
+        .replace("{ ", "{")
+        .replace(" }", "}")
+        .replace(", }", "}")
+        .replace(",}", "}")
+        .replace(" ,", ",");
+    let normalized = normalized.trim_end_matches(';').trim_end();
+
+    format!("{indent}{normalized};")
+}
+
+const MIN_PREFIX_LEN: usize = 10;
+const BREAK_PUNCTUATION: [char; 6] = [',', ';', '.', ')', ']', '}'];
+
+/// Wraps `//`, `///`, and `//!` comment lines that exceed their configured
+/// width limit, breaking the body with [`greedy_break`] and re-indenting
+/// each continuation line to match the original comment marker. A line
+/// suppressed by an `ignore-style:commentwidth`/`ignore-style-file:commentwidth`
+/// directive, or that's just a standalone URL, is left untouched — wrapping
+/// a bare URL would corrupt it. String literals are out of scope; see the
+/// module doc comment.
+pub fn wrap_comments(content: &str, config: &Config, directives: &Directives) -> String {
+    let mut output = String::new();
+
+    for (idx, line) in content.lines().enumerate() {
+        let line_no = idx + 1;
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+
+        let (marker, limit) = if trimmed.starts_with("///") {
+            ("///", config.doc_comment_width)
+        } else if trimmed.starts_with("//!") {
+            ("//!", config.doc_comment_width)
+        } else if trimmed.starts_with("//") {
+            ("//", config.comment_width)
+        } else {
+            output.push_str(line);
+            output.push('\n');
+            continue;
+        };
+
+        if line.chars().count() <= limit
+            || directives.suppresses(line_no, CheckKind::CommentWidth)
+            || url::is_url_exempt(line)
+        {
+            output.push_str(line);
+            output.push('\n');
+            continue;
+        }
+
+        let body = trimmed[marker.len()..].trim_start();
+        let prefix_width = indent.chars().count() + marker.chars().count() + 1;
+        let Some(available) = limit.checked_sub(prefix_width).filter(|&w| w > 0) else {
+            output.push_str(line);
+            output.push('\n');
+            continue;
+        };
+
+        for piece in greedy_break(body, available) {
+            output.push_str(indent);
+            output.push_str(marker);
+            output.push(' ');
+            output.push_str(&piece);
+            output.push('\n');
+        }
+    }
+
+    if !content.ends_with('\n') {
+        output.pop();
+    }
+
+    output
+}
+
+/// Breaks `text` into pieces that each fit within `max_width`: prefers
+/// breaking at the last whitespace at or before the limit, falls back to
+/// breaking just after the last line-break punctuation before the limit,
+/// and leaves a piece whole (rather than splitting mid-word) if neither is
+/// found.
+fn greedy_break(text: &str, max_width: usize) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut remaining = text.to_string();
+
+    loop {
+        let chars: Vec<char> = remaining.chars().collect();
+        if chars.len() <= max_width {
+            if !remaining.is_empty() {
+                pieces.push(remaining.clone());
+            }
+            break;
+        }
+
+        let break_at = last_whitespace_at_or_before(&chars, max_width)
+            .filter(|&idx| idx >= MIN_PREFIX_LEN)
+            .or_else(|| last_punctuation_before(&chars, max_width).map(|idx| idx + 1));
+
+        let Some(break_at) = break_at else {
+            pieces.push(remaining.clone());
+            break;
+        };
+
+        let piece: String = chars[..break_at].iter().collect::<String>().trim_end().to_string();
+        pieces.push(piece);
+
+        remaining = chars[break_at..].iter().collect::<String>().trim_start().to_string();
+    }
+
+    pieces
+}
+
+fn last_whitespace_at_or_before(chars: &[char], max_width: usize) -> Option<usize> {
+    let limit = max_width.min(chars.len().saturating_sub(1));
+    (0..=limit).rev().find(|&idx| chars[idx].is_whitespace())
+}
+
+fn last_punctuation_before(chars: &[char], max_width: usize) -> Option<usize> {
+    let limit = max_width.min(chars.len().saturating_sub(1));
+    (0..=limit).rev().find(|&idx| BREAK_PUNCTUATION.contains(&chars[idx]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::directives;
+    use std::path::PathBuf;
+
+    fn no_directives() -> Directives {
+        directives::parse(&PathBuf::from("test.rs"), &[]).0
+    }
+
+    #[test]
+    fn collapses_a_multi_line_use_group() {
+        let content = "use std::{\n    fs,\n    path::Path,\n};\n";
+        let fixed = fix_imports(content, &no_directives());
+        assert_eq!(fixed, "use std::{fs, path::Path};\n");
+    }
+
+    #[test]
+    fn tracks_nested_braces_to_find_the_end_of_the_group() {
+        let content = "use std::{\n    collections::{HashMap, HashSet},\n    fs,\n};\n";
+        let fixed = fix_imports(content, &no_directives());
+        assert_eq!(fixed, "use std::{collections::{HashMap, HashSet}, fs};\n");
+    }
+
+    #[test]
+    fn leaves_an_already_single_line_group_untouched() {
+        let content = "use std::fs;\n";
+        let fixed = fix_imports(content, &no_directives());
+        assert_eq!(fixed, content);
+    }
+
+    #[test]
+    fn leaves_a_group_suppressed_by_a_line_directive_untouched() {
+        let content = "use std::{ // ignore-style:imports\n    fs,\n    path::Path,\n};\n";
+        let (directives, _) = directives::parse(&PathBuf::from("test.rs"), &content.lines().collect::<Vec<_>>());
+        let fixed = fix_imports(content, &directives);
+        assert_eq!(fixed, content);
+    }
+
+    #[test]
+    fn leaves_a_group_suppressed_by_a_file_directive_untouched() {
+        let content = "// ignore-style-file:imports\nuse std::{\n    fs,\n    path::Path,\n};\n";
+        let (directives, _) = directives::parse(&PathBuf::from("test.rs"), &content.lines().collect::<Vec<_>>());
+        let fixed = fix_imports(content, &directives);
+        assert_eq!(fixed, content);
+    }
+
+    fn narrow_config() -> Config {
+        Config {
+            max_width: 20,
+            comment_width: 20,
+            doc_comment_width: 20,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn wraps_a_comment_that_exceeds_the_width_limit() {
+        let content = "// this comment is much too long to fit on one line\n";
+        let fixed = wrap_comments(content, &narrow_config(), &no_directives());
+        assert!(fixed.lines().all(|line| line.chars().count() <= 20), "{fixed:?}");
+        assert!(fixed.lines().count() > 1, "{fixed:?}");
+    }
+
+    #[test]
+    fn leaves_a_standalone_url_comment_untouched_even_past_the_width_limit() {
+        let content = "// https://example.com/some/very/long/path/that/is/over/the/limit\n";
+        let fixed = wrap_comments(content, &narrow_config(), &no_directives());
+        assert_eq!(fixed, content);
+    }
+
+    #[test]
+    fn leaves_a_short_comment_untouched() {
+        let content = "// short\n";
+        let fixed = wrap_comments(content, &narrow_config(), &no_directives());
+        assert_eq!(fixed, content);
+    }
+
+    #[test]
+    fn leaves_a_comment_suppressed_by_a_file_directive_untouched() {
+        let content = "// ignore-style-file:commentwidth\n// this comment is much too long to fit on one line\n";
+        let (directives, _) = directives::parse(&PathBuf::from("test.rs"), &content.lines().collect::<Vec<_>>());
+        let fixed = wrap_comments(content, &narrow_config(), &directives);
+        assert_eq!(fixed, content);
+    }
+
+    #[test]
+    fn greedy_break_leaves_text_whole_when_no_break_point_is_found_before_the_limit() {
+        let pieces = greedy_break("averylongsinglewordwithnobreakpointatall", 10);
+        assert_eq!(pieces, vec!["averylongsinglewordwithnobreakpointatall".to_string()]);
+    }
+
+    #[test]
+    fn greedy_break_falls_back_to_punctuation_when_whitespace_is_too_close_to_the_start() {
+        let pieces = greedy_break("a, a very long word after a short break", 12);
+        assert_eq!(pieces[0], "a,");
+    }
+}
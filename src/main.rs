@@ -0,0 +1,105 @@
+//! Entry point for the style-check GitHub Action: walks the given paths (or
+//! every `.rs` file under the current directory), runs the checks in
+//! [`checks`] over each one, and reports failures as GitHub Actions error
+//! annotations.
+
+mod checks;
+mod comments;
+mod config;
+mod directives;
+mod fix;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let fix_mode = args.iter().any(|arg| arg == "--fix");
+    let paths: Vec<PathBuf> = args
+        .iter()
+        .filter(|arg| *arg != "--fix")
+        .map(PathBuf::from)
+        .collect();
+    let paths = if paths.is_empty() {
+        collect_rust_files(Path::new("."))
+    } else {
+        paths
+    };
+
+    let mut had_errors = false;
+    for path in paths {
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) => {
+                eprintln!("failed to read {}: {err}", path.display());
+                had_errors = true;
+                continue;
+            }
+        };
+
+        let (config, config_error) = config::discover(&path);
+        if let Some(error) = config_error {
+            println!("{error}");
+            had_errors = true;
+        }
+
+        let content = if fix_mode {
+            let lines: Vec<&str> = content.lines().collect();
+            let (directives, _) = directives::parse(&path, &lines);
+            let fixed = fix::fix_imports(&content, &directives);
+
+            // `fix_imports` can shift line numbers (by collapsing multi-line
+            // groups), so directives are re-parsed against its output before
+            // `wrap_comments` looks anything up by line number.
+            let fixed_lines: Vec<&str> = fixed.lines().collect();
+            let (directives, _) = directives::parse(&path, &fixed_lines);
+            let fixed = fix::wrap_comments(&fixed, &config, &directives);
+            if fixed != content {
+                if let Err(err) = fs::write(&path, &fixed) {
+                    eprintln!("failed to write {}: {err}", path.display());
+                    had_errors = true;
+                }
+            }
+            fixed
+        } else {
+            content
+        };
+
+        for error in checks::run_checks(&path, &content, &config) {
+            println!("{error}");
+            had_errors = true;
+        }
+    }
+
+    if had_errors {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn collect_rust_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    visit(root, &mut files);
+    files
+}
+
+fn visit(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if path.file_name().and_then(|n| n.to_str()) == Some("target") {
+                continue;
+            }
+            visit(&path, files);
+        } else if path.extension().and_then(|e| e.to_str()) == Some("rs") {
+            files.push(path);
+        }
+    }
+}